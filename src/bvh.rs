@@ -0,0 +1,149 @@
+use std::f64::INFINITY;
+use std::{ops::Range, sync::Arc};
+
+use crate::raycasting::{Hittable, HittableList, Intersection, Ray};
+
+#[derive(Clone)]
+pub struct Aabb {
+    pub x: Range<f64>,
+    pub y: Range<f64>,
+    pub z: Range<f64>,
+}
+
+impl Aabb {
+    pub fn new(x: Range<f64>, y: Range<f64>, z: Range<f64>) -> Self {
+        Aabb { x, y, z }
+    }
+
+    pub fn empty() -> Self {
+        Aabb::new(INFINITY..-INFINITY, INFINITY..-INFINITY, INFINITY..-INFINITY)
+    }
+
+    pub fn surrounding(a: &Aabb, b: &Aabb) -> Self {
+        Aabb::new(
+            f64::min(a.x.start, b.x.start)..f64::max(a.x.end, b.x.end),
+            f64::min(a.y.start, b.y.start)..f64::max(a.y.end, b.y.end),
+            f64::min(a.z.start, b.z.start)..f64::max(a.z.end, b.z.end),
+        )
+    }
+
+    fn axis(&self, n: usize) -> &Range<f64> {
+        match n {
+            0 => &self.x,
+            1 => &self.y,
+            _ => &self.z,
+        }
+    }
+
+    fn longest_axis(&self) -> usize {
+        let (dx, dy, dz) = (self.x.end - self.x.start, self.y.end - self.y.start, self.z.end - self.z.start);
+        if dx > dy && dx > dz {
+            0
+        } else if dy > dz {
+            1
+        } else {
+            2
+        }
+    }
+
+    pub fn hit(&self, r: &Ray, t_range: &Range<f64>) -> bool {
+        let mut range = t_range.clone();
+
+        for axis in 0..3 {
+            let bounds = self.axis(axis);
+            let (orig, dir) = match axis {
+                0 => (r.orig.x, r.dir.x),
+                1 => (r.orig.y, r.dir.y),
+                _ => (r.orig.z, r.dir.z),
+            };
+
+            let inv_dir = 1.0 / dir;
+            let mut t0 = (bounds.start - orig) * inv_dir;
+            let mut t1 = (bounds.end - orig) * inv_dir;
+            if inv_dir < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            range.start = if t0 > range.start { t0 } else { range.start };
+            range.end = if t1 < range.end { t1 } else { range.end };
+
+            if range.end <= range.start {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+struct EmptyHittable;
+
+impl Hittable for EmptyHittable {
+    fn hit(&self, _r: &Ray, _t_range: &Range<f64>, _intersection: &mut Intersection) -> bool {
+        false
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        Aabb::empty()
+    }
+}
+
+pub struct BvhNode {
+    left: Arc<dyn Hittable + Send + Sync>,
+    right: Arc<dyn Hittable + Send + Sync>,
+    bbox: Aabb,
+}
+
+impl BvhNode {
+    pub fn new(list: HittableList) -> Self {
+        BvhNode::from_objects(list.into_objects())
+    }
+
+    fn from_objects(mut objects: Vec<Arc<dyn Hittable + Send + Sync>>) -> Self {
+        if objects.is_empty() {
+            return BvhNode {
+                left: Arc::new(EmptyHittable),
+                right: Arc::new(EmptyHittable),
+                bbox: Aabb::empty(),
+            };
+        }
+
+        let bbox = objects.iter().fold(Aabb::empty(), |acc, object| Aabb::surrounding(&acc, &object.bounding_box()));
+        let axis = bbox.longest_axis();
+
+        let (left, right): (Arc<dyn Hittable + Send + Sync>, Arc<dyn Hittable + Send + Sync>) = match objects.len() {
+            1 => (Arc::clone(&objects[0]), Arc::clone(&objects[0])),
+            2 => (Arc::clone(&objects[0]), Arc::clone(&objects[1])),
+            _ => {
+                objects.sort_by(|a, b| {
+                    a.bounding_box().axis(axis).start.partial_cmp(&b.bounding_box().axis(axis).start).unwrap()
+                });
+                let right_objects = objects.split_off(objects.len() / 2);
+                (
+                    Arc::new(BvhNode::from_objects(objects)) as Arc<dyn Hittable + Send + Sync>,
+                    Arc::new(BvhNode::from_objects(right_objects)) as Arc<dyn Hittable + Send + Sync>,
+                )
+            }
+        };
+
+        BvhNode { left, right, bbox }
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, r: &Ray, t_range: &Range<f64>, intersection: &mut Intersection) -> bool {
+        if !self.bbox.hit(r, t_range) {
+            return false;
+        }
+
+        let hit_left = self.left.hit(r, t_range, intersection);
+        let right_range = if hit_left { t_range.start..intersection.time } else { t_range.clone() };
+        let hit_right = self.right.hit(r, &right_range, intersection);
+
+        hit_left || hit_right
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.bbox.clone()
+    }
+}
@@ -1,16 +1,44 @@
 use cgmath::{ElementWise, InnerSpace};
 use image::*;
 use indicatif::{MultiProgress, ProgressBar};
+use std::collections::VecDeque;
 use std::f64::INFINITY;
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 
 use crate::raycasting::Hittable;
 use crate::raycasting::{Color3, Point3, Vec3};
-use crate::raycasting::{HittableList, Intersection, Ray};
+use crate::raycasting::{Intersection, Ray};
 // use crate::materials::Material;
 use crate::rand_util::Rand;
 
+const TILE_SIZE: u32 = 32;
+
+struct Tile {
+	x: u32,
+	y: u32,
+	w: u32,
+	h: u32,
+}
+
+fn build_tiles(width: u32, height: u32) -> VecDeque<Tile> {
+	let mut tiles = VecDeque::new();
+
+	let mut y = 0;
+	while y < height {
+		let h = TILE_SIZE.min(height - y);
+		let mut x = 0;
+		while x < width {
+			let w = TILE_SIZE.min(width - x);
+			tiles.push_back(Tile { x, y, w, h });
+			x += TILE_SIZE;
+		}
+		y += TILE_SIZE;
+	}
+
+	tiles
+}
+
 pub struct Camera {
     pub aspect_ratio: f64,      // Ratio of Image width / height
     pub image_width: u32,       // Rendered Image width in pixels
@@ -22,6 +50,11 @@ pub struct Camera {
 	pub vup: Vec3,              // Camera-relative "up" direction
 	pub defocus_angle: f64,     // Variation angle of rays through each pixel
 	pub focus_dist: f64,        // Distance from camera lookpoint to plane of perfect focus
+	pub shutter_open: f64,      // Time the shutter opens at, for motion blur
+	pub shutter_close: f64,     // Time the shutter closes at, for motion blur
+	pub background: Color3,    // Color returned for rays that hit nothing
+	pub thread_count: Option<usize>, // Worker threads to render with; None uses available_parallelism
+	pub seed: Option<u64>,      // Base RNG seed; None renders with fresh entropy each run
     image_height: u32,          // Rendered Image height in pixels
     center: Point3,             // Camera Center
 	pixel_samples_scale: f64,   // Color scale factor for a sum of pixel samples
@@ -45,6 +78,11 @@ impl Camera {
 			v_fov: 90.0,
 			defocus_angle: 0.0,
 			focus_dist: 10.0,
+			shutter_open: 0.0,
+			shutter_close: 0.0,
+			background: Color3::new(0.0, 0.0, 0.0),
+			thread_count: None,
+			seed: None,
 			lookfrom: Point3::new(0.0,0.0,0.0),
 			lookat: Point3::new(0.0,0.0,-1.0),
 			vup: Vec3::new(0.0,1.0,0.0),
@@ -104,46 +142,62 @@ impl Camera {
 		self.defocus_disk_v = v * defocus_radius;
     }
 
-	pub fn render_parallel(s: Self, path: &String, world: HittableList) {
+	pub fn render_parallel(s: Self, path: &String, world: Arc<dyn Hittable + Send + Sync>) {
 		let (tx, rx) = mpsc::channel();
 		let se = Arc::new(s);
-		let wo = Arc::new(world);
+		let wo = world;
 		let mut imgbuf = image::RgbImage::new(se.image_width, se.image_height);
 
 		let (iw, ih, samples, depth, sample_scale) = (se.image_width, se.image_height, se.samples_per_pixel, se.max_depth, se.pixel_samples_scale);
-		let step = iw / 10;
+		let thread_count = se.thread_count.unwrap_or_else(|| {
+			thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+		});
+
+		let tiles = Arc::new(Mutex::new(build_tiles(iw, ih)));
 
 		let multiprogress = MultiProgress::new();
+		let progress = multiprogress.add(ProgressBar::new((iw * ih) as u64));
 
-		for i in 0..10 {
-			let (curr, next) = (i * step, (i + 1) * step);
-			let (se1, wo1) = (Arc::clone(&se), Arc::clone(&wo));
+		let mut workers = Vec::with_capacity(thread_count);
+		for _ in 0..thread_count {
+			let (se1, wo1, tiles1) = (Arc::clone(&se), Arc::clone(&wo), Arc::clone(&tiles));
 			let tx1 = tx.clone();
-			let p1 = multiprogress.add(ProgressBar::new((step * ih) as u64));
-			thread::spawn(move || {
-				let mut rng: Rand = Rand::new();
-				for x in curr..next {
-					for y in 0..ih {
-						let mut pixel_color = Color3::new(0.0, 0.0, 0.0);
-						for _ in 0..samples {
-							let r: Ray = se1.get_ray(x, y, &mut rng);
-							pixel_color += se1.ray_color(&r, depth, &wo1, &mut rng)
-						}
+			workers.push(thread::spawn(move || {
+				loop {
+					let tile = match tiles1.lock().unwrap().pop_front() {
+						Some(tile) => tile,
+						None => break,
+					};
+
+					let mut rng: Rand = match se1.seed {
+						Some(seed) => Rand::from_seed(seed ^ ((tile.x as u64) * 0x9E3779B9) ^ ((tile.y as u64) << 17)),
+						None => Rand::new(),
+					};
+
+					for x in tile.x..(tile.x + tile.w) {
+						for y in tile.y..(tile.y + tile.h) {
+							let mut pixel_color = Color3::new(0.0, 0.0, 0.0);
+							for _ in 0..samples {
+								let r: Ray = se1.get_ray(x, y, &mut rng);
+								pixel_color += se1.ray_color(&r, depth, wo1.as_ref(), &mut rng)
+							}
 
-						p1.inc(1);
-						tx1.send((x,y,Camera::to_rgb(sample_scale * pixel_color))).unwrap();
+							tx1.send((x,y,Camera::to_rgb(sample_scale * pixel_color))).unwrap();
+						}
 					}
 				}
-			});
+			}));
 		}
 		drop(tx);
 
-		let progress = multiprogress.add(ProgressBar::new((iw * ih) as u64));
 		for (rx, ry, rp) in rx {
 			imgbuf.put_pixel(rx, ry, rp);
 			progress.inc(1);
 		}
 
+		for worker in workers {
+			worker.join().unwrap();
+		}
 
 		imgbuf.save(format!("images/{}.png", path)).unwrap();
 	}
@@ -184,8 +238,9 @@ impl Camera {
 		
 		let ray_origin = if self.defocus_angle <= 0.0 {self.center} else {self.defocus_disk_sample(rng)};
 		let ray_direction = pixel_sample - ray_origin;
-		
-		Ray::new(ray_origin, ray_direction)
+		let time = self.shutter_open + rng.random_double() * (self.shutter_close - self.shutter_open);
+
+		Ray::new(ray_origin, ray_direction, time)
 	}
 
 	fn linear_to_gamma(n: f64) -> f64 {
@@ -202,24 +257,25 @@ impl Camera {
 		Rgb([x, y, z])
 	}
 	
-	fn ray_color(&self, r: &Ray, depth: u32, world: &HittableList, rng: &mut Rand) -> Color3 {
+	fn ray_color(&self, r: &Ray, depth: u32, world: &(dyn Hittable + Send + Sync), rng: &mut Rand) -> Color3 {
 		// Exceeded the bounce limit
 		if depth <= 0 {
 			return Color3::new(0.0, 0.0, 0.0);
 		}
 
 		let mut rec: Intersection = Intersection::new();
-	
-		if world.hit(r, &(0.001..INFINITY), &mut rec) {
-			if let Some((scattered, attenuation)) = rec.mat.scatter(r, &rec, rng) {
-				return attenuation.mul_element_wise(self.ray_color(&scattered, depth - 1, world, rng));
-			}
-			return Color3::new(0.0, 0.0, 0.0);
+
+		if !world.hit(r, &(0.001..INFINITY), &mut rec) {
+			return self.background;
 		}
-	
-		let unit = r.dir.normalize();
-		let alpha = 0.5 * (unit.y + 1.0);
-		Color3::new(1.0, 1.0, 1.0) * (1.0 - alpha) + alpha * Color3::new(0.5, 0.7, 1.0)
+
+		let emitted = rec.mat.emitted();
+
+		if let Some((scattered, attenuation)) = rec.mat.scatter(r, &rec, rng) {
+			return emitted + attenuation.mul_element_wise(self.ray_color(&scattered, depth - 1, world, rng));
+		}
+
+		emitted
 	}
 
 	fn defocus_disk_sample(&self, rng: &mut Rand) -> Vec3 {
@@ -1,17 +1,19 @@
 use std::sync::Arc;
 use std::{env, process};
 
+mod bvh;
 mod camera;
 mod raycasting;
 mod materials;
 mod rand_util;
+use bvh::BvhNode;
 use camera::Camera;
 use cgmath::MetricSpace;
-use materials::{Dielectric, Lambertian, Metal};
+use materials::{Dielectric, DiffuseLight, Lambertian, Metal};
 use rand::Rng;
 use raycasting::HittableList;
 use raycasting::{Point3, Color3, Vec3};
-use raycasting::Sphere;
+use raycasting::{MovingSphere, Sphere};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -76,10 +78,11 @@ fn main() {
             let center = Point3::new((a as f64) + 0.9 * rng.gen_range(0.0..1.0), 0.2, (b as f64) + 0.9 * rng.gen_range(0.0..1.0));
 
             if center.distance2(Point3::new(4.0,0.2,0.0)) > 0.81 {
-                if choose_mat < 0.8 { // Diffuse
+                if choose_mat < 0.8 { // Diffuse, bouncing across the shutter interval
                     let albedo = Color3::new(rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0));
                     let material = Arc::new(Lambertian::new(albedo));
-                    world.add(Arc::new(Sphere::new(center, 0.2, material)));
+                    let center1 = center + Vec3::new(0.0, rng.gen_range(0.0..0.5), 0.0);
+                    world.add(Arc::new(MovingSphere::new(center, center1, 0.2, material, 0.0, 1.0)));
                 }
                 else if choose_mat < 0.95 { // Metal
                     let albedo = Color3::new(rng.gen_range(0.5..1.0), rng.gen_range(0.5..1.0), rng.gen_range(0.5..1.0));
@@ -104,6 +107,9 @@ fn main() {
     let material3 = Arc::new(Metal::new(Color3::new(0.7,0.6,0.5), 0.0));
     world.add(Arc::new(Sphere::new(Point3::new(4.0,1.0,0.0), 1.0, material3)));
 
+    let light_material = Arc::new(DiffuseLight::new(Color3::new(4.0,4.0,4.0)));
+    world.add(Arc::new(Sphere::new(Point3::new(0.0,7.0,0.0), 2.0, light_material)));
+
     let mut camera = Camera::default();
 
     camera.aspect_ratio = 16.0 / 9.0;
@@ -119,7 +125,13 @@ fn main() {
     camera.defocus_angle = 0.6;
     camera.focus_dist = 10.0;
 
+    camera.shutter_open = 0.0;
+    camera.shutter_close = 1.0;
+
+    camera.background = Color3::new(0.7, 0.8, 1.0);
+
     camera.initialize();
+    let world = Arc::new(BvhNode::new(world));
     Camera::render_parallel(camera, &args[1], world);
     // camera.render(&args[1], &world);
 }
@@ -6,6 +6,10 @@ use crate::rand_util::Rand;
 
 pub trait Material {
 	fn scatter(&self, r_in: &Ray, rec: &Intersection, rng: &mut Rand) -> Option<(Ray, Color3)>;
+
+	fn emitted(&self) -> Color3 {
+		Color3::new(0.0, 0.0, 0.0)
+	}
 }
 
 pub struct Lambertian {
@@ -21,12 +25,12 @@ impl Lambertian {
 }
 
 impl Material for Lambertian {
-	fn scatter(&self, _r_in: &Ray, rec: &Intersection, rng: &mut Rand) -> Option<(Ray, Color3)> {
+	fn scatter(&self, r_in: &Ray, rec: &Intersection, rng: &mut Rand) -> Option<(Ray, Color3)> {
 		let mut scatter_direction = rec.norm + rng.random_unit_vec();
 		if vec_near_zero(&scatter_direction) {
 			scatter_direction = rec.norm;
 		}
-		Some((Ray::new(rec.position, scatter_direction), self.albedo.clone()))
+		Some((Ray::new(rec.position, scatter_direction, r_in.time), self.albedo.clone()))
 	}
 }
 
@@ -51,7 +55,7 @@ impl Material for Metal {
 		if dot(reflected, rec.norm) <= 0.0 {
 			return None;
 		}
-		Some((Ray::new(rec.position, reflected), self.albedo.clone()))
+		Some((Ray::new(rec.position, reflected, r_in.time), self.albedo.clone()))
 	}
 }
 
@@ -92,7 +96,29 @@ impl Material for Dielectric {
 			direction = refract(&r_in.dir.normalize(), &rec.norm, ri);
 		}
 
-		Some((Ray::new(rec.position, direction), Color3::new(1.0, 1.0, 1.0)))
+		Some((Ray::new(rec.position, direction, r_in.time), Color3::new(1.0, 1.0, 1.0)))
+	}
+}
+
+pub struct DiffuseLight {
+	emit: Color3,
+}
+
+impl DiffuseLight {
+	pub fn new(emit: Color3) -> Self {
+		DiffuseLight {
+			emit
+		}
+	}
+}
+
+impl Material for DiffuseLight {
+	fn scatter(&self, _r_in: &Ray, _rec: &Intersection, _rng: &mut Rand) -> Option<(Ray, Color3)> {
+		None
+	}
+
+	fn emitted(&self) -> Color3 {
+		self.emit
 	}
 }
 
@@ -17,6 +17,13 @@ impl Rand {
 		}
 	}
 
+	pub fn from_seed(seed: u64) -> Self {
+		Rand {
+			range: Uniform::from(0.0..1.0),
+			rng: SmallRng::seed_from_u64(seed),
+		}
+	}
+
 	pub fn random_double(&mut self) -> f64 {
 		self.range.sample(&mut self.rng)
 	}
@@ -1,5 +1,6 @@
 use cgmath::dot;
 use std::{ops::Range, sync::Arc};
+use crate::bvh::Aabb;
 use crate::materials::{Material,Metal};
 
 pub type Color3 = cgmath::Vector3<f64>;
@@ -9,6 +10,7 @@ pub type Vec3 = cgmath::Vector3<f64>;
 pub struct Ray {
     pub orig: Point3,
     pub dir: Vec3,
+    pub time: f64,
 }
 
 pub struct Sphere {
@@ -17,8 +19,17 @@ pub struct Sphere {
     mat: Arc<dyn Material + Send + Sync>
 }
 
+pub struct MovingSphere {
+    center0: Point3,
+    center1: Point3,
+    radius: f64,
+    mat: Arc<dyn Material + Send + Sync>,
+    time0: f64,
+    time1: f64,
+}
+
 pub struct Intersection {
-    time: f64,
+    pub(crate) time: f64,
     pub position: Point3,
     pub norm: Vec3,
     pub mat: Arc<dyn Material + Send + Sync>,
@@ -31,6 +42,7 @@ pub struct HittableList {
 
 pub trait Hittable {
     fn hit(&self, r: &Ray, t_range: &Range<f64>, intersection: &mut Intersection) -> bool;
+    fn bounding_box(&self) -> Aabb;
 }
 
 impl HittableList {
@@ -43,6 +55,10 @@ impl HittableList {
     pub fn add(&mut self, object: Arc<dyn Hittable + Send + Sync>) {
         self.objects.push(object);
     }
+
+    pub(crate) fn into_objects(self) -> Vec<Arc<dyn Hittable + Send + Sync>> {
+        self.objects
+    }
 }
 
 impl Hittable for HittableList {
@@ -59,6 +75,10 @@ impl Hittable for HittableList {
 
         hit_anything
     }
+
+    fn bounding_box(&self) -> Aabb {
+        self.objects.iter().fold(Aabb::empty(), |acc, object| Aabb::surrounding(&acc, &object.bounding_box()))
+    }
 }
 
 impl Intersection {
@@ -81,8 +101,8 @@ impl Intersection {
 }
 
 impl Ray {
-    pub fn new(orig: Point3, dir: Vec3) -> Self {
-        Ray { orig, dir }
+    pub fn new(orig: Point3, dir: Vec3, time: f64) -> Self {
+        Ray { orig, dir, time }
     }
 
     pub fn at(&self, t: f64) -> Point3 {
@@ -132,4 +152,78 @@ impl Hittable for Sphere {
 
         true
     }
+
+    fn bounding_box(&self) -> Aabb {
+        Aabb::new(
+            (self.center.x - self.radius)..(self.center.x + self.radius),
+            (self.center.y - self.radius)..(self.center.y + self.radius),
+            (self.center.z - self.radius)..(self.center.z + self.radius),
+        )
+    }
+}
+
+impl MovingSphere {
+    pub fn new(center0: Point3, center1: Point3, radius: f64, mat: Arc<dyn Material + Send + Sync>, time0: f64, time1: f64) -> Self {
+        MovingSphere {
+            center0,
+            center1,
+            radius,
+            mat,
+            time0,
+            time1,
+        }
+    }
+
+    fn center_at(&self, time: f64) -> Point3 {
+        self.center0 + ((time - self.time0) / (self.time1 - self.time0)) * (self.center1 - self.center0)
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, r: &Ray, t_range: &Range<f64>, intersection: &mut Intersection) -> bool {
+        let center = self.center_at(r.time);
+        let oc = center - r.orig;
+        let (a, h, c) = (
+            dot(r.dir, r.dir),
+            dot(r.dir, oc),
+            dot(oc, oc) - self.radius * self.radius
+        );
+        let discriminant = h * h - a * c;
+
+        if discriminant < 0.0 {
+            return false;
+        }
+
+        let sqrtd = discriminant.sqrt();
+
+        let mut time = (h - sqrtd) / a;
+        if time <= t_range.start || time >= t_range.end {
+            time = (h + sqrtd) / a;
+            if time <= t_range.start || time >= t_range.end {
+                return false;
+            }
+        }
+
+        intersection.time = time;
+        intersection.position = r.at(time);
+        intersection.norm = (intersection.position - center) / self.radius;
+        intersection.set_face_normal(r, intersection.norm);
+        intersection.mat = Arc::clone(&self.mat);
+
+        true
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        let box0 = Aabb::new(
+            (self.center0.x - self.radius)..(self.center0.x + self.radius),
+            (self.center0.y - self.radius)..(self.center0.y + self.radius),
+            (self.center0.z - self.radius)..(self.center0.z + self.radius),
+        );
+        let box1 = Aabb::new(
+            (self.center1.x - self.radius)..(self.center1.x + self.radius),
+            (self.center1.y - self.radius)..(self.center1.y + self.radius),
+            (self.center1.z - self.radius)..(self.center1.z + self.radius),
+        );
+        Aabb::surrounding(&box0, &box1)
+    }
 }